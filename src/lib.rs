@@ -12,15 +12,44 @@
 
 
 use std::{fmt::{self, Display, Formatter}, ops::Deref};
+use std::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use std::ffi::{c_char, CStr};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::str::Utf8Error;
+use std::char::DecodeUtf16Error;
+
+
+
+/// Error returned when a string's length or capacity doesn't fit in a `u32`
+///
+/// This can only happen on 64-bit (or larger) targets, where a `String`/`&str` longer than
+/// 4 GiB would otherwise have its length or capacity silently truncated by the `as u32` cast
+/// used internally by [`FFIStr::new`] and [`FFIString::new`]
+///
+/// <br>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LenOverflow;
+
+impl fmt::Display for LenOverflow {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "string length or capacity does not fit in a u32")
+	}
+}
+
+impl std::error::Error for LenOverflow {}
 
 
 
 /// FFI version of &str
-/// 
+///
 /// <br>
 /// 
 /// Features:
 /// - `fn new(&str) -> Self`
+/// - `fn try_new(&str) -> Result<Self, LenOverflow>`
+/// - `unsafe fn from_raw_parts(*const u8, u32) -> Self`
+/// - `unsafe fn try_from_raw_parts(*const u8, u32) -> Result<Self, Utf8Error>`
 /// - `fn as_str(&self) -> &str`
 /// - `impl Deref<Target = str>`
 /// - `impl Copy, Clone`
@@ -38,7 +67,13 @@ pub struct FFIStr<'a> {
 
 impl<'a> FFIStr<'a> {
 	/// Creates a new FFIStr from a string slice, copying only pointers
+	///
+	/// # Panics (debug only)
+	///
+	/// Panics in debug builds if `from.len()` is greater than `u32::MAX`, since the length would
+	/// otherwise be silently truncated. Use [`FFIStr::try_new`] to handle this case instead of panicking
 	pub fn new(from: &'a str) -> Self {
+		debug_assert!(from.len() <= u32::MAX as usize, "FFIStr::new: string is too long to represent with a u32 length, use FFIStr::try_new instead");
 		unsafe {
 			Self {
 				ptr: &*from.as_ptr(),
@@ -46,6 +81,40 @@ impl<'a> FFIStr<'a> {
 			}
 		}
 	}
+	/// Creates a new FFIStr from a string slice, copying only pointers
+	///
+	/// Unlike [`FFIStr::new`], this returns an error instead of silently truncating the length
+	/// when `from.len()` is greater than `u32::MAX`
+	pub fn try_new(from: &'a str) -> Result<Self, LenOverflow> {
+		if from.len() > u32::MAX as usize {
+			return Err(LenOverflow);
+		}
+		Ok(Self::new(from))
+	}
+	/// Rebuilds an FFIStr from a pointer and length that arrived over an FFI boundary
+	///
+	/// # Safety
+	///
+	/// `ptr` must be non-null and must point to `len` consecutive, valid UTF-8 bytes that live
+	/// for at least `'a`
+	pub unsafe fn from_raw_parts(ptr: *const u8, len: u32) -> Self {
+		Self {
+			ptr: &*ptr,
+			len,
+		}
+	}
+	/// Rebuilds an FFIStr from a pointer and length that arrived over an FFI boundary, validating
+	/// that the bytes are UTF-8 before constructing the type
+	///
+	/// # Safety
+	///
+	/// `ptr` must be non-null and must point to `len` consecutive, valid bytes (not necessarily
+	/// UTF-8) that live for at least `'a`
+	pub unsafe fn try_from_raw_parts(ptr: *const u8, len: u32) -> Result<Self, Utf8Error> {
+		let bytes = core::slice::from_raw_parts(ptr, len as usize);
+		core::str::from_utf8(bytes)?;
+		Ok(Self::from_raw_parts(ptr, len))
+	}
 	/// Creates a string slice, copying only pointers
 	/// 
 	/// Also, the function `to_string()` (implementation of fmt::Display) creates a new String, copying the underlying data
@@ -118,6 +187,11 @@ impl StrToFFI for str {
 /// 
 /// Features:
 /// - `fn new(&str) -> Self`
+/// - `fn try_new(&str) -> Result<Self, LenOverflow>`
+/// - `unsafe fn from_raw_parts(*mut u8, u32, u32) -> Self`
+/// - `unsafe fn try_from_raw_parts(*mut u8, u32, u32) -> Result<Self, Utf8Error>`
+/// - `const fn empty() -> Self` / `const fn zeroed() -> Self`
+/// - `fn is_null(&self) -> bool`, `fn is_empty(&self) -> bool`
 /// - `fn as_str(&self) -> &str`
 /// - `impl Deref<Target = str>`
 /// - `impl Clone`
@@ -136,8 +210,16 @@ pub struct FFIString {
 
 impl FFIString {
 	/// Creates a new FFIString from a String, copying only pointers (if you pass String) or all underlying data (for anything else)
+	///
+	/// # Panics (debug only)
+	///
+	/// Panics in debug builds if `from.len()` or `from.capacity()` is greater than `u32::MAX`,
+	/// since they would otherwise be silently truncated. Use [`FFIString::try_new`] to handle
+	/// this case instead of panicking
 	pub fn new(from: impl Into<String>) -> Self {
 		let mut from = from.into();
+		debug_assert!(from.len() <= u32::MAX as usize, "FFIString::new: string is too long to represent with a u32 length, use FFIString::try_new instead");
+		debug_assert!(from.capacity() <= u32::MAX as usize, "FFIString::new: string is too large to represent with a u32 capacity, use FFIString::try_new instead");
 		let output = Self {
 			ptr: from.as_mut_ptr(),
 			len: from.len() as u32,
@@ -146,10 +228,53 @@ impl FFIString {
 		std::mem::forget(from);
 		output
 	}
+	/// Creates a new FFIString from a String, copying only pointers (if you pass String) or all underlying data (for anything else)
+	///
+	/// Unlike [`FFIString::new`], this returns an error instead of silently truncating the length
+	/// or capacity when either is greater than `u32::MAX`
+	pub fn try_new(from: impl Into<String>) -> Result<Self, LenOverflow> {
+		let from = from.into();
+		if from.len() > u32::MAX as usize || from.capacity() > u32::MAX as usize {
+			return Err(LenOverflow);
+		}
+		Ok(Self::new(from))
+	}
+	/// Rebuilds an FFIString from a pointer, length, and capacity that arrived over an FFI boundary
+	///
+	/// # Safety
+	///
+	/// `ptr` must be non-null and must point to a buffer of `cap` bytes, allocated by Rust's
+	/// global allocator with the alignment of `u8`, of which the first `len` bytes are valid
+	/// UTF-8. Ownership of the buffer is transferred to the returned FFIString, whose `Drop`
+	/// frees it with `String::from_raw_parts`; a buffer allocated by a foreign allocator must
+	/// not be passed here, or `Drop` will attempt to free it with the wrong allocator
+	pub unsafe fn from_raw_parts(ptr: *mut u8, len: u32, cap: u32) -> Self {
+		Self {
+			ptr,
+			len,
+			cap,
+		}
+	}
+	/// Rebuilds an FFIString from a pointer, length, and capacity that arrived over an FFI
+	/// boundary, validating that the bytes are UTF-8 before constructing the type
+	///
+	/// # Safety
+	///
+	/// Same allocator requirements as [`FFIString::from_raw_parts`], except the first `len` bytes
+	/// are not required to already be valid UTF-8
+	pub unsafe fn try_from_raw_parts(ptr: *mut u8, len: u32, cap: u32) -> Result<Self, Utf8Error> {
+		let bytes = core::slice::from_raw_parts(ptr, len as usize);
+		core::str::from_utf8(bytes)?;
+		Ok(Self::from_raw_parts(ptr, len, cap))
+	}
 	/// Creates a new String, copying only pointers
-	/// 
+	///
 	/// Also, the function `to_string()` (implementation of fmt::Display) creates a new String, copying the underlying data
 	pub fn into_string(self) -> String {
+		if self.ptr.is_null() {
+			std::mem::forget(self);
+			return String::new();
+		}
 		unsafe {
 			let output = String::from_raw_parts(self.ptr, self.len as usize, self.cap as usize);
 			std::mem::forget(self);
@@ -157,15 +282,46 @@ impl FFIString {
 		}
 	}
 	/// Creates a string slice, copying only pointers
+	///
+	/// Returns `""` without dereferencing anything if this FFIString is in the [`FFIString::empty`] state
 	pub fn as_str(&self) -> &str {
+		if self.ptr.is_null() {
+			return "";
+		}
 		unsafe {
 			core::str::from_raw_parts(self.ptr, self.len as usize)
 		}
 	}
+	/// Creates a zero-initialized FFIString representing "no string", safe for foreign code to
+	/// pass by zeroing a struct's memory
+	///
+	/// `as_str` returns `""` and `Drop` is a no-op for a value in this state
+	pub const fn empty() -> Self {
+		Self {
+			ptr: core::ptr::null_mut(),
+			len: 0,
+			cap: 0,
+		}
+	}
+	/// Alias for [`FFIString::empty`]
+	pub const fn zeroed() -> Self {
+		Self::empty()
+	}
+	/// Returns true if this FFIString is in the zero-initialized [`FFIString::empty`] state
+	pub const fn is_null(&self) -> bool {
+		self.ptr.is_null()
+	}
+	/// Returns true if this FFIString holds zero bytes (this is also true for [`FFIString::empty`])
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
 }
 
 impl Drop for FFIString {
 	fn drop(&mut self) {
+		if self.ptr.is_null() {
+			return;
+		}
 		unsafe {
 			String::from_raw_parts(self.ptr, self.len as usize, self.cap as usize);
 		}
@@ -211,6 +367,9 @@ impl Into<String> for FFIString {
 
 impl Clone for FFIString {
 	fn clone(&self) -> Self {
+		if self.is_null() {
+			return Self::empty();
+		}
 		Self::new(self.to_string())
 	}
 }
@@ -228,3 +387,801 @@ impl StringToFFI for String {
 		FFIString::new(self)
 	}
 }
+
+
+
+/// FFI version of a nul-terminated `const char*`, for interop with plain C functions
+///
+/// Unlike [`FFIStr`], this is not a fat pointer; it carries no length, so the string's end is
+/// found by scanning for the terminating nul byte, exactly like a C string
+///
+/// <br>
+///
+/// Features:
+/// - `unsafe fn from_ptr(*const c_char) -> Self`
+/// - `fn to_str(&self) -> Result<&str, Utf8Error>`
+/// - `fn as_ptr(&self) -> *const c_char`
+/// - `impl Copy, Clone`
+/// - `impl Debug`
+///
+/// <br>
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct FFICStr<'a> {
+	ptr: NonNull<u8>,
+	_marker: PhantomData<&'a u8>,
+}
+
+impl<'a> FFICStr<'a> {
+	/// Wraps a raw, nul-terminated `const char*` that was received over FFI
+	///
+	/// # Safety
+	///
+	/// `ptr` must be non-null and must point to a valid, nul-terminated sequence of bytes that
+	/// lives for at least `'a`
+	pub unsafe fn from_ptr(ptr: *const c_char) -> Self {
+		Self {
+			ptr: NonNull::new_unchecked(ptr as *mut u8),
+			_marker: PhantomData,
+		}
+	}
+	/// Scans for the terminating nul and validates the bytes before it as UTF-8
+	pub fn to_str(&self) -> Result<&'a str, Utf8Error> {
+		unsafe {
+			CStr::from_ptr(self.ptr.as_ptr() as *const c_char).to_str()
+		}
+	}
+	/// Returns the underlying `const char*`
+	pub fn as_ptr(&self) -> *const c_char {
+		self.ptr.as_ptr() as *const c_char
+	}
+}
+
+impl fmt::Debug for FFICStr<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self.to_str() {
+			Ok(str) => write!(f, "\"{str}\""),
+			Err(_) => write!(f, "<invalid utf8 c string>"),
+		}
+	}
+}
+
+/// FFI version of a nul-terminated, owned C string, allocated with Rust's global allocator
+///
+/// <br>
+///
+/// Features:
+/// - `fn new(&str) -> Self`
+/// - `fn as_ffi_c_str(&self) -> FFICStr`
+/// - `fn to_str(&self) -> Result<&str, Utf8Error>`
+/// - `fn as_ptr(&self) -> *const c_char`
+/// - `impl Debug`
+/// - Correctly drops underlying data
+///
+/// <br>
+#[repr(C)]
+pub struct FFICString {
+	ptr: NonNull<u8>,
+	len: u32,
+}
+
+impl FFICString {
+	/// Creates a new FFICString from a string slice, copying the underlying data and appending a
+	/// terminating nul byte
+	///
+	/// `from` may contain interior nul bytes (they're copied through like any other byte); the
+	/// allocated length is recorded on the type itself, so `Drop` doesn't need to rescan for a
+	/// terminator to know how much to free, and a `to_str()` on a string with interior nuls will
+	/// simply report the portion before the first one, like a normal C string
+	pub fn new(from: &str) -> Self {
+		let bytes = from.as_bytes();
+		let len = bytes.len();
+		debug_assert!(len <= u32::MAX as usize, "FFICString::new: string is too long to represent with a u32 length");
+		unsafe {
+			let layout = Layout::array::<u8>(len + 1).expect("string is too large to allocate");
+			let ptr = alloc(layout);
+			if ptr.is_null() {
+				handle_alloc_error(layout);
+			}
+			std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, len);
+			*ptr.add(len) = 0;
+			Self {
+				ptr: NonNull::new_unchecked(ptr),
+				len: len as u32,
+			}
+		}
+	}
+	/// Borrows this FFICString as an FFICStr, copying only the pointer
+	pub fn as_ffi_c_str(&self) -> FFICStr<'_> {
+		FFICStr {
+			ptr: self.ptr,
+			_marker: PhantomData,
+		}
+	}
+	/// Scans for the terminating nul and validates the bytes before it as UTF-8
+	pub fn to_str(&self) -> Result<&str, Utf8Error> {
+		self.as_ffi_c_str().to_str()
+	}
+	/// Returns the underlying `const char*`
+	pub fn as_ptr(&self) -> *const c_char {
+		self.ptr.as_ptr() as *const c_char
+	}
+}
+
+impl Drop for FFICString {
+	fn drop(&mut self) {
+		unsafe {
+			let layout = Layout::array::<u8>(self.len as usize + 1).expect("string is too large to allocate");
+			dealloc(self.ptr.as_ptr(), layout);
+		}
+	}
+}
+
+impl fmt::Debug for FFICString {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		self.as_ffi_c_str().fmt(f)
+	}
+}
+
+impl<'a> From<&'a str> for FFICString {
+	fn from(value: &'a str) -> Self {
+		Self::new(value)
+	}
+}
+
+
+
+/// Growable, mutable FFI version of String, for assembling a string piece by piece across an FFI boundary
+///
+/// <br>
+///
+/// Features:
+/// - `fn with_capacity(u32) -> Self`
+/// - `fn push_str(&mut self, &str)`
+/// - `fn push_ffi_str(&mut self, FFIStr)`
+/// - `fn len(&self) -> u32`
+/// - `fn is_empty(&self) -> bool`
+/// - `fn clear(&mut self)`
+/// - `fn as_str(&self) -> &str`
+/// - `fn into_ffi_string(self) -> FFIString`
+/// - `impl Debug, Display`
+/// - Correctly drops underlying data
+///
+/// <br>
+#[repr(C)]
+pub struct FFIStringBuilder {
+	ptr: *mut u8,
+	len: u32,
+	cap: u32,
+}
+
+impl FFIStringBuilder {
+	/// Creates a new, empty FFIStringBuilder with at least the given capacity
+	pub fn with_capacity(cap: u32) -> Self {
+		let mut buf = String::with_capacity(cap as usize);
+		debug_assert!(buf.capacity() <= u32::MAX as usize, "FFIStringBuilder::with_capacity: capacity is too large to represent with a u32");
+		let output = Self {
+			ptr: buf.as_mut_ptr(),
+			len: 0,
+			cap: buf.capacity() as u32,
+		};
+		std::mem::forget(buf);
+		output
+	}
+	/// Appends a string slice to the end of this builder, reallocating if needed
+	pub fn push_str(&mut self, str: &str) {
+		debug_assert!(self.len as usize + str.len() <= u32::MAX as usize, "FFIStringBuilder::push_str: result is too long to represent with a u32 length");
+		unsafe {
+			let mut buf = String::from_raw_parts(self.ptr, self.len as usize, self.cap as usize);
+			buf.push_str(str);
+			self.ptr = buf.as_mut_ptr();
+			self.len = buf.len() as u32;
+			self.cap = buf.capacity() as u32;
+			std::mem::forget(buf);
+		}
+	}
+	/// Appends an FFIStr to the end of this builder, reallocating if needed
+	pub fn push_ffi_str(&mut self, str: FFIStr) {
+		self.push_str(str.as_str());
+	}
+	/// Returns the number of bytes currently in this builder
+	pub fn len(&self) -> u32 {
+		self.len
+	}
+	/// Returns true if this builder contains no bytes
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+	/// Removes all bytes from this builder, keeping the underlying allocation for reuse
+	pub fn clear(&mut self) {
+		unsafe {
+			let mut buf = String::from_raw_parts(self.ptr, self.len as usize, self.cap as usize);
+			buf.clear();
+			self.ptr = buf.as_mut_ptr();
+			self.len = 0;
+			self.cap = buf.capacity() as u32;
+			std::mem::forget(buf);
+		}
+	}
+	/// Creates a string slice, copying only pointers
+	pub fn as_str(&self) -> &str {
+		unsafe {
+			core::str::from_raw_parts(self.ptr, self.len as usize)
+		}
+	}
+	/// Finalizes this builder into an FFIString, transferring ownership without reallocating
+	pub fn into_ffi_string(self) -> FFIString {
+		let output = FFIString {
+			ptr: self.ptr,
+			len: self.len,
+			cap: self.cap,
+		};
+		std::mem::forget(self);
+		output
+	}
+}
+
+impl Drop for FFIStringBuilder {
+	fn drop(&mut self) {
+		unsafe {
+			String::from_raw_parts(self.ptr, self.len as usize, self.cap as usize);
+		}
+	}
+}
+
+impl fmt::Debug for FFIStringBuilder {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "\"{}\"", self.as_str())
+	}
+}
+
+impl Display for FFIStringBuilder {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+
+
+/// FFI version of a UTF-16 string slice, for interop with Windows `WCHAR*`, JNI `jstring`, and ICU APIs
+///
+/// Unlike [`FFIStr`], there is no `&str` view: `len` counts UTF-16 code units, not bytes, so use
+/// [`FFIStr16::to_string`] to decode
+///
+/// <br>
+///
+/// Features:
+/// - `fn new(&[u16]) -> Self`
+/// - `fn as_units(&self) -> &[u16]`
+/// - `fn to_string(&self) -> Result<String, DecodeUtf16Error>`
+/// - `impl Copy, Clone`
+/// - `impl Debug`
+///
+/// <br>
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct FFIStr16<'a> {
+	ptr: &'a u16,
+	len: u32,
+}
+
+impl<'a> FFIStr16<'a> {
+	/// Creates a new FFIStr16 from a slice of UTF-16 code units, copying only pointers
+	pub fn new(units: &'a [u16]) -> Self {
+		debug_assert!(units.len() <= u32::MAX as usize, "FFIStr16::new: too many units to represent with a u32 length");
+		unsafe {
+			Self {
+				ptr: &*units.as_ptr(),
+				len: units.len() as u32,
+			}
+		}
+	}
+	/// Creates a slice of UTF-16 code units, copying only pointers
+	pub fn as_units(&self) -> &'a [u16] {
+		unsafe {
+			core::slice::from_raw_parts(self.ptr, self.len as usize)
+		}
+	}
+	/// Decodes the underlying UTF-16 code units into a new String
+	pub fn to_string(&self) -> Result<String, DecodeUtf16Error> {
+		char::decode_utf16(self.as_units().iter().copied()).collect()
+	}
+}
+
+impl fmt::Debug for FFIStr16<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self.to_string() {
+			Ok(str) => write!(f, "\"{str}\""),
+			Err(_) => write!(f, "<invalid utf-16 string>"),
+		}
+	}
+}
+
+/// FFI version of an owned UTF-16 string, for interop with Windows `WCHAR*`, JNI `jstring`, and ICU APIs
+///
+/// <br>
+///
+/// Features:
+/// - `fn from_str(&str) -> Self`
+/// - `fn as_ffi_str16(&self) -> FFIStr16`
+/// - `fn as_units(&self) -> &[u16]`
+/// - `fn to_string(&self) -> Result<String, DecodeUtf16Error>`
+/// - `impl Debug`
+/// - Correctly drops underlying data
+///
+/// <br>
+#[repr(C)]
+pub struct FFIString16 {
+	ptr: *mut u16,
+	len: u32,
+}
+
+impl FFIString16 {
+	/// Creates a new FFIString16 by encoding a string slice as UTF-16
+	#[allow(clippy::should_implement_trait)] // intentionally named to mirror encode_utf16's source string, not std::str::FromStr
+	pub fn from_str(from: &str) -> Self {
+		let units: Vec<u16> = from.encode_utf16().collect();
+		debug_assert!(units.len() <= u32::MAX as usize, "FFIString16::from_str: string is too long to represent with a u32 unit length");
+		let mut boxed = units.into_boxed_slice();
+		let len = boxed.len() as u32;
+		let ptr = boxed.as_mut_ptr();
+		std::mem::forget(boxed);
+		Self { ptr, len }
+	}
+	/// Borrows this FFIString16 as an FFIStr16, copying only the pointer
+	pub fn as_ffi_str16(&self) -> FFIStr16<'_> {
+		unsafe {
+			FFIStr16 {
+				ptr: &*self.ptr,
+				len: self.len,
+			}
+		}
+	}
+	/// Creates a slice of UTF-16 code units, copying only pointers
+	pub fn as_units(&self) -> &[u16] {
+		self.as_ffi_str16().as_units()
+	}
+	/// Decodes the underlying UTF-16 code units into a new String
+	pub fn to_string(&self) -> Result<String, DecodeUtf16Error> {
+		self.as_ffi_str16().to_string()
+	}
+}
+
+impl Drop for FFIString16 {
+	fn drop(&mut self) {
+		unsafe {
+			drop(Box::from_raw(core::ptr::slice_from_raw_parts_mut(self.ptr, self.len as usize)));
+		}
+	}
+}
+
+impl fmt::Debug for FFIString16 {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		self.as_ffi_str16().fmt(f)
+	}
+}
+
+impl<'a> From<&'a str> for FFIString16 {
+	fn from(value: &'a str) -> Self {
+		Self::from_str(value)
+	}
+}
+
+
+
+/// FFI-safe equivalent of `Option<FFIString>`, for `extern "C"` functions that need to return
+/// "no string" distinctly from an empty string
+///
+/// <br>
+///
+/// Features:
+/// - `const fn none() -> Self`
+/// - `const fn some(FFIString) -> Self`
+/// - `fn is_some(&self) -> bool`, `fn is_none(&self) -> bool`
+/// - `fn into_option(self) -> Option<FFIString>`
+/// - `impl From<Option<FFIString>>`
+/// - `impl Debug`
+///
+/// <br>
+#[repr(C)]
+pub struct FFIOptionString {
+	is_some: bool,
+	value: FFIString,
+}
+
+impl FFIOptionString {
+	/// Creates the "absent" state; `value` is [`FFIString::empty`], so dropping it is a no-op
+	pub const fn none() -> Self {
+		Self {
+			is_some: false,
+			value: FFIString::empty(),
+		}
+	}
+	/// Creates the "present" state, holding the given FFIString
+	pub const fn some(value: FFIString) -> Self {
+		Self {
+			is_some: true,
+			value,
+		}
+	}
+	/// Returns true if this holds a string
+	pub const fn is_some(&self) -> bool {
+		self.is_some
+	}
+	/// Returns true if this represents an absent string
+	pub const fn is_none(&self) -> bool {
+		!self.is_some
+	}
+	/// Converts back into a Rust `Option<FFIString>`
+	pub fn into_option(self) -> Option<FFIString> {
+		if self.is_some {
+			Some(self.value)
+		} else {
+			None
+		}
+	}
+}
+
+impl From<Option<FFIString>> for FFIOptionString {
+	fn from(value: Option<FFIString>) -> Self {
+		match value {
+			Some(value) => Self::some(value),
+			None => Self::none(),
+		}
+	}
+}
+
+impl fmt::Debug for FFIOptionString {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		if self.is_some {
+			fmt::Debug::fmt(&self.value, f)
+		} else {
+			write!(f, "None")
+		}
+	}
+}
+
+
+
+/// FFI version of &\[u8\], for moving raw bytes (OS paths, binary blobs) across `extern "C"` without a UTF-8 invariant
+///
+/// <br>
+///
+/// Features:
+/// - `fn new(&[u8]) -> Self`
+/// - `fn as_bytes(&self) -> &[u8]`
+/// - `fn to_str(&self) -> Result<&str, Utf8Error>`
+/// - `fn to_ffi_str(&self) -> Result<FFIStr, Utf8Error>`
+/// - `impl Copy, Clone`
+/// - `impl Debug`
+/// - `impl From<&[u8]>`
+///
+/// <br>
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct FFIBytes<'a> {
+	ptr: &'a u8,
+	len: u32,
+}
+
+impl<'a> FFIBytes<'a> {
+	/// Creates a new FFIBytes from a byte slice, copying only pointers
+	pub fn new(from: &'a [u8]) -> Self {
+		debug_assert!(from.len() <= u32::MAX as usize, "FFIBytes::new: slice is too long to represent with a u32 length");
+		unsafe {
+			Self {
+				ptr: &*from.as_ptr(),
+				len: from.len() as u32,
+			}
+		}
+	}
+	/// Creates a byte slice, copying only pointers
+	pub fn as_bytes(&self) -> &'a [u8] {
+		unsafe {
+			core::slice::from_raw_parts(self.ptr, self.len as usize)
+		}
+	}
+	/// Validates the underlying bytes as UTF-8, returning a string slice that copies only pointers
+	pub fn to_str(&self) -> Result<&'a str, Utf8Error> {
+		core::str::from_utf8(self.as_bytes())
+	}
+	/// Validates the underlying bytes as UTF-8, returning an FFIStr that copies only pointers
+	pub fn to_ffi_str(&self) -> Result<FFIStr<'a>, Utf8Error> {
+		self.to_str().map(FFIStr::new)
+	}
+}
+
+impl fmt::Debug for FFIBytes<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self.to_str() {
+			Ok(str) => write!(f, "\"{str}\""),
+			Err(_) => write!(f, "{:?}", self.as_bytes()),
+		}
+	}
+}
+
+impl<'a> From<&'a [u8]> for FFIBytes<'a> {
+	fn from(value: &'a [u8]) -> Self {
+		Self::new(value)
+	}
+}
+
+/// FFI version of a `Vec<u8>`, for moving raw bytes (OS paths, binary blobs) across `extern "C"` without a UTF-8 invariant
+///
+/// <br>
+///
+/// Features:
+/// - `fn new(Vec<u8>) -> Self`
+/// - `fn as_bytes(&self) -> &[u8]`
+/// - `fn into_bytes(self) -> Vec<u8>`
+/// - `fn to_str(&self) -> Result<&str, Utf8Error>`
+/// - `fn to_ffi_str(&self) -> Result<FFIStr, Utf8Error>`
+/// - `impl Clone, Debug`
+/// - `impl From<Vec<u8>>, From<&[u8]>`
+/// - Correctly drops underlying data
+///
+/// <br>
+#[repr(C)]
+pub struct FFIByteBuf {
+	ptr: *mut u8,
+	len: u32,
+	cap: u32,
+}
+
+impl FFIByteBuf {
+	/// Creates a new FFIByteBuf from anything convertible to a `Vec<u8>`, copying only pointers
+	/// (if you pass a `Vec<u8>`) or all underlying data (for anything else)
+	pub fn new(from: impl Into<Vec<u8>>) -> Self {
+		let mut from = from.into();
+		debug_assert!(from.len() <= u32::MAX as usize, "FFIByteBuf::new: buffer is too long to represent with a u32 length");
+		debug_assert!(from.capacity() <= u32::MAX as usize, "FFIByteBuf::new: buffer is too large to represent with a u32 capacity");
+		let output = Self {
+			ptr: from.as_mut_ptr(),
+			len: from.len() as u32,
+			cap: from.capacity() as u32,
+		};
+		std::mem::forget(from);
+		output
+	}
+	/// Creates a new `Vec<u8>`, copying only pointers
+	pub fn into_bytes(self) -> Vec<u8> {
+		unsafe {
+			let output = Vec::from_raw_parts(self.ptr, self.len as usize, self.cap as usize);
+			std::mem::forget(self);
+			output
+		}
+	}
+	/// Creates a byte slice, copying only pointers
+	pub fn as_bytes(&self) -> &[u8] {
+		unsafe {
+			core::slice::from_raw_parts(self.ptr, self.len as usize)
+		}
+	}
+	/// Validates the underlying bytes as UTF-8, returning a string slice that copies only pointers
+	pub fn to_str(&self) -> Result<&str, Utf8Error> {
+		core::str::from_utf8(self.as_bytes())
+	}
+	/// Validates the underlying bytes as UTF-8, returning an FFIStr that copies only pointers
+	pub fn to_ffi_str(&self) -> Result<FFIStr<'_>, Utf8Error> {
+		self.to_str().map(FFIStr::new)
+	}
+}
+
+impl Drop for FFIByteBuf {
+	fn drop(&mut self) {
+		unsafe {
+			Vec::from_raw_parts(self.ptr, self.len as usize, self.cap as usize);
+		}
+	}
+}
+
+impl fmt::Debug for FFIByteBuf {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self.to_str() {
+			Ok(str) => write!(f, "\"{str}\""),
+			Err(_) => write!(f, "{:?}", self.as_bytes()),
+		}
+	}
+}
+
+impl From<Vec<u8>> for FFIByteBuf {
+	fn from(value: Vec<u8>) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<'a> From<&'a [u8]> for FFIByteBuf {
+	fn from(value: &'a [u8]) -> Self {
+		Self::new(value.to_vec())
+	}
+}
+
+impl Clone for FFIByteBuf {
+	fn clone(&self) -> Self {
+		Self::new(self.as_bytes().to_vec())
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ffi_c_string_round_trips() {
+		let str = FFICString::new("hello");
+		assert_eq!(str.to_str(), Ok("hello"));
+	}
+
+	#[test]
+	fn ffi_c_string_with_interior_nul_does_not_corrupt_drop() {
+		// Regression test: Drop used to rescan for the first NUL to figure out how much to
+		// free, which undersized the freed allocation whenever the source string contained an
+		// interior NUL byte
+		let str = FFICString::new("ab\0cd");
+		assert_eq!(str.to_str(), Ok("ab"));
+		drop(str);
+	}
+
+	#[test]
+	fn ffi_str_from_raw_parts_round_trips() {
+		let original = FFIStr::new("hello");
+		let rebuilt = unsafe { FFIStr::from_raw_parts(original.as_str().as_ptr(), 5) };
+		assert_eq!(rebuilt.as_str(), "hello");
+	}
+
+	#[test]
+	fn ffi_str_try_from_raw_parts_rejects_invalid_utf8() {
+		let bytes = [0xff_u8, 0xfe];
+		let result = unsafe { FFIStr::try_from_raw_parts(bytes.as_ptr(), bytes.len() as u32) };
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn ffi_string_from_raw_parts_round_trips() {
+		let original = FFIString::new("hello");
+		let ptr = original.ptr;
+		let len = original.len;
+		let cap = original.cap;
+		std::mem::forget(original);
+		let rebuilt = unsafe { FFIString::from_raw_parts(ptr, len, cap) };
+		assert_eq!(rebuilt.as_str(), "hello");
+	}
+
+	#[test]
+	fn ffi_string_try_from_raw_parts_rejects_invalid_utf8() {
+		let mut buf = vec![0xff_u8, 0xfe];
+		let ptr = buf.as_mut_ptr();
+		let len = buf.len() as u32;
+		let cap = buf.capacity() as u32;
+		std::mem::forget(buf);
+		let result = unsafe { FFIString::try_from_raw_parts(ptr, len, cap) };
+		assert!(result.is_err());
+		// the buffer was rejected before ownership was transferred, so free it ourselves
+		unsafe {
+			drop(Vec::from_raw_parts(ptr, len as usize, cap as usize));
+		}
+	}
+
+	#[test]
+	fn ffi_string_builder_assembles_pieces() {
+		let mut builder = FFIStringBuilder::with_capacity(4);
+		builder.push_str("hello, ");
+		builder.push_ffi_str(FFIStr::new("world"));
+		assert_eq!(builder.len(), 12);
+		assert_eq!(builder.as_str(), "hello, world");
+		let str = builder.into_ffi_string();
+		assert_eq!(str.as_str(), "hello, world");
+	}
+
+	#[test]
+	fn ffi_string_builder_clear_keeps_allocation_usable() {
+		let mut builder = FFIStringBuilder::with_capacity(0);
+		builder.push_str("temporary");
+		builder.clear();
+		assert!(builder.is_empty());
+		assert_eq!(builder.as_str(), "");
+		builder.push_str("reused");
+		assert_eq!(builder.as_str(), "reused");
+	}
+
+	#[test]
+	fn ffi_str_try_new_accepts_normal_strings() {
+		assert_eq!(FFIStr::try_new("hello").unwrap().as_str(), "hello");
+	}
+
+	#[test]
+	fn ffi_string_try_new_accepts_normal_strings() {
+		assert_eq!(FFIString::try_new("hello").unwrap().as_str(), "hello");
+	}
+
+	#[test]
+	fn ffi_str_try_new_rejects_len_over_u32_max() {
+		// Allocating an actual >4GiB &str isn't practical in a test, so fake an oversized length
+		// via from_raw_parts instead. This is sound here because FFIStr::try_new only inspects
+		// `len` before bailing out with LenOverflow - it never reads through the pointer, and a
+		// borrowed &str has no Drop to mismanage afterward
+		let buf = [0u8];
+		let oversized = unsafe { core::str::from_raw_parts(buf.as_ptr(), u32::MAX as usize + 1) };
+		assert!(matches!(FFIStr::try_new(oversized), Err(LenOverflow)));
+	}
+
+	#[test]
+	fn ffi_string_empty_clone_stays_null() {
+		// Regression test: Clone used to go through Self::new(self.to_string()), which always
+		// produces a real, non-null, zero-capacity FFIString, silently losing the sentinel
+		let empty = FFIString::empty();
+		assert!(empty.is_null());
+		assert!(empty.clone().is_null());
+	}
+
+	#[test]
+	fn ffi_string_empty_is_empty_but_not_every_empty_is_null() {
+		assert!(FFIString::empty().is_null());
+		assert!(FFIString::empty().is_empty());
+		assert!(!FFIString::new("").is_null());
+		assert!(FFIString::new("").is_empty());
+	}
+
+	#[test]
+	fn ffi_option_string_round_trips_through_into_option() {
+		assert!(FFIOptionString::none().into_option().is_none());
+		let some = FFIOptionString::some(FFIString::new("hello"));
+		assert_eq!(some.into_option().map(|str| str.into_string()), Some("hello".to_string()));
+	}
+
+	#[test]
+	fn ffi_str_16_round_trips() {
+		let units: Vec<u16> = "hello".encode_utf16().collect();
+		let str = FFIStr16::new(&units);
+		assert_eq!(str.to_string(), Ok("hello".to_string()));
+	}
+
+	#[test]
+	fn ffi_string_16_round_trips() {
+		let str = FFIString16::from_str("hello");
+		assert_eq!(str.to_string(), Ok("hello".to_string()));
+	}
+
+	#[test]
+	fn ffi_str_16_to_string_rejects_unpaired_surrogate() {
+		let units = [0xD800_u16];
+		let str = FFIStr16::new(&units);
+		assert!(str.to_string().is_err());
+	}
+
+	#[test]
+	fn ffi_bytes_to_str_accepts_valid_utf8() {
+		let bytes = FFIBytes::new(b"hello");
+		assert_eq!(bytes.to_str(), Ok("hello"));
+		assert_eq!(bytes.to_ffi_str().unwrap().as_str(), "hello");
+	}
+
+	#[test]
+	fn ffi_bytes_to_str_rejects_invalid_utf8() {
+		let bytes = FFIBytes::new(&[0xff, 0xfe]);
+		assert!(bytes.to_str().is_err());
+		assert!(bytes.to_ffi_str().is_err());
+	}
+
+	#[test]
+	fn ffi_byte_buf_round_trips() {
+		let buf = FFIByteBuf::new(vec![1, 2, 3]);
+		assert_eq!(buf.as_bytes(), &[1, 2, 3]);
+		let clone = buf.clone();
+		assert_eq!(buf.into_bytes(), vec![1, 2, 3]);
+		assert_eq!(clone.as_bytes(), &[1, 2, 3]);
+	}
+
+	#[test]
+	fn ffi_byte_buf_to_str_accepts_valid_utf8_and_rejects_invalid() {
+		let valid = FFIByteBuf::new(b"hello".to_vec());
+		assert_eq!(valid.to_str(), Ok("hello"));
+		assert_eq!(valid.to_ffi_str().unwrap().as_str(), "hello");
+
+		let invalid = FFIByteBuf::new(vec![0xff, 0xfe]);
+		assert!(invalid.to_str().is_err());
+		assert!(invalid.to_ffi_str().is_err());
+	}
+}